@@ -0,0 +1,123 @@
+use ink_storage::Mapping;
+use openbrush::traits::{
+    AccountId,
+    Storage,
+};
+
+/// Role identifier, as produced by `ink_lang::selector_id!("ROLE_NAME")`.
+pub type RoleType = u32;
+
+/// The role every role's admin defaults to, unless reassigned with `_set_role_admin`.
+pub const DEFAULT_ADMIN_ROLE: RoleType = 0;
+
+/// Storage used by the [`AccessControl`] extension.
+#[derive(Default, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Data {
+    pub roles: Mapping<(RoleType, AccountId), bool>,
+    pub admin_roles: Mapping<RoleType, RoleType>,
+}
+
+/// The AccessControl error type. Contract will throw one of these errors.
+#[derive(strum_macros::AsRefStr, Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum AccessControlError {
+    /// Returned if caller doesn't hold the required role.
+    MissingRole,
+    /// Returned if the account already holds the role being granted.
+    RoleRedundant,
+}
+
+/// Contract module which allows children to implement role-based access control mechanisms. Each
+/// role has a single admin role that is allowed to grant and revoke it, defaulting to
+/// `DEFAULT_ADMIN_ROLE` until reassigned with `_set_role_admin`.
+///
+/// This module is used through embedding of [`Data`] and implementation of the `AccessControl`
+/// trait.
+#[openbrush::trait_definition]
+pub trait AccessControl: Storage<Data> {
+    /// Returns `true` if `account` holds `role`.
+    #[ink(message)]
+    fn has_role(&self, role: RoleType, account: AccountId) -> bool {
+        self._has_role(role, &account)
+    }
+
+    /// Returns the admin role that controls `role`.
+    #[ink(message)]
+    fn get_role_admin(&self, role: RoleType) -> RoleType {
+        self._get_role_admin(role)
+    }
+
+    /// Grants `role` to `account`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AccessControlError::MissingRole` if the caller doesn't hold `role`'s admin role.
+    ///
+    /// Returns `AccessControlError::RoleRedundant` if `account` already holds `role`.
+    #[ink(message)]
+    fn grant_role(&mut self, role: RoleType, account: AccountId) -> Result<(), AccessControlError> {
+        self._check_role(self._get_role_admin(role), &Self::env().caller())?;
+        if self._has_role(role, &account) {
+            return Err(AccessControlError::RoleRedundant)
+        }
+        self._do_grant_role(role, account);
+        Ok(())
+    }
+
+    /// Revokes `role` from `account`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AccessControlError::MissingRole` if the caller doesn't hold `role`'s admin role.
+    #[ink(message)]
+    fn revoke_role(&mut self, role: RoleType, account: AccountId) -> Result<(), AccessControlError> {
+        self._check_role(self._get_role_admin(role), &Self::env().caller())?;
+        self._do_revoke_role(role, account);
+        Ok(())
+    }
+
+    /// Revokes `role` from the caller.
+    #[ink(message)]
+    fn renounce_role(&mut self, role: RoleType) -> Result<(), AccessControlError> {
+        self._do_revoke_role(role, Self::env().caller());
+        Ok(())
+    }
+
+    // Helper functions
+
+    /// Returns `true` if `account` holds `role`.
+    fn _has_role(&self, role: RoleType, account: &AccountId) -> bool {
+        self.data().roles.get(&(role, *account)).unwrap_or(false)
+    }
+
+    /// Returns `AccessControlError::MissingRole` unless `account` holds `role`.
+    fn _check_role(&self, role: RoleType, account: &AccountId) -> Result<(), AccessControlError> {
+        match self._has_role(role, account) {
+            true => Ok(()),
+            false => Err(AccessControlError::MissingRole),
+        }
+    }
+
+    /// Returns the admin role that controls `role`, defaulting to `DEFAULT_ADMIN_ROLE`.
+    fn _get_role_admin(&self, role: RoleType) -> RoleType {
+        self.data().admin_roles.get(&role).unwrap_or(DEFAULT_ADMIN_ROLE)
+    }
+
+    /// Sets `admin_role` as the role allowed to grant/revoke `role`.
+    fn _set_role_admin(&mut self, role: RoleType, admin_role: RoleType) {
+        self.data_mut().admin_roles.insert(&role, &admin_role);
+    }
+
+    /// Grants `role` to `account`, bypassing the admin-role check. Intended for use from
+    /// constructors and by other trait extensions (e.g. granting `DEFAULT_ADMIN_ROLE` to the
+    /// deployer).
+    fn _do_grant_role(&mut self, role: RoleType, account: AccountId) {
+        self.data_mut().roles.insert(&(role, account), &true);
+    }
+
+    /// Revokes `role` from `account`, bypassing the admin-role check.
+    fn _do_revoke_role(&mut self, role: RoleType, account: AccountId) {
+        self.data_mut().roles.insert(&(role, account), &false);
+    }
+}
@@ -0,0 +1,79 @@
+use openbrush::traits::Storage;
+
+/// Storage used by the [`Pausable`] extension.
+#[derive(Default, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Data {
+    pub paused: bool,
+}
+
+/// The Pausable error type. Contract will throw one of these errors.
+#[derive(strum_macros::AsRefStr, Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PausableError {
+    /// Returned if the contract is already paused.
+    Paused,
+    /// Returned if the contract is not paused.
+    NotPaused,
+}
+
+/// Contract module which allows children to implement an emergency stop mechanism. The default
+/// `pause` and `unpause` messages do not check the caller: any account can call them.
+///
+/// This is the single pause state shared by every token standard in this crate: embed [`Data`]
+/// and implement `Pausable` on a PSP1155- or PSP37-based contract alike, so both read one flag
+/// rather than each token tracking its own. On PSP37 (through `PSP37Pausable`) pausing halts
+/// mint, burn and transfer; on PSP1155 it halts only the `PSP1155Burnable` burn messages, and
+/// PSP1155 transfers and minting are not halted.
+#[openbrush::trait_definition]
+pub trait Pausable: Storage<Data> {
+    /// Returns `true` if the contract is paused, and `false` otherwise.
+    #[ink(message)]
+    fn paused(&self) -> bool {
+        self.data().paused
+    }
+
+    /// Triggers the paused state.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PausableError::Paused` if the contract is already paused.
+    #[ink(message)]
+    fn pause(&mut self) -> Result<(), PausableError> {
+        self._when_not_paused()?;
+        self.data_mut().paused = true;
+        Ok(())
+    }
+
+    /// Returns the contract to normal state.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PausableError::NotPaused` if the contract is not paused.
+    #[ink(message)]
+    fn unpause(&mut self) -> Result<(), PausableError> {
+        self._when_paused()?;
+        self.data_mut().paused = false;
+        Ok(())
+    }
+
+    // Helper functions
+
+    /// Checks whether the contract is paused and returns `PausableError::Paused` if so.
+    ///
+    /// Call this guard at the start of any message that must halt while the contract is paused.
+    fn _when_not_paused(&self) -> Result<(), PausableError> {
+        match self.data().paused {
+            true => Err(PausableError::Paused),
+            false => Ok(()),
+        }
+    }
+
+    /// Checks whether the contract is paused and returns `PausableError::NotPaused` if not.
+    fn _when_paused(&self) -> Result<(), PausableError> {
+        match self.data().paused {
+            true => Ok(()),
+            false => Err(PausableError::NotPaused),
+        }
+    }
+}
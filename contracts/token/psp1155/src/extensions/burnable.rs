@@ -1,54 +1,119 @@
 /// Extension of [`PSP1155`] that allows token holders to destroy their tokens
 use crate::traits::*;
-use brush::traits::{
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+use openbrush::traits::{
     AccountId,
     Balance,
 };
-use ink_prelude::vec::Vec;
 
-#[brush::trait_definition]
+#[cfg(feature = "access_control")]
+use access_control::{
+    AccessControl,
+    AccessControlError,
+};
+
+#[cfg(feature = "access_control")]
+pub use access_control::RoleType;
+
+#[cfg(feature = "pausable")]
+use pausable::{
+    Pausable,
+    PausableError,
+};
+
+/// Role required to call `burn_from`/`burn_batch_from` when the `access_control` feature is
+/// enabled, instead of the default `is_approved_for_all` check.
+#[cfg(feature = "access_control")]
+pub const BURNER: RoleType = ink_lang::selector_id!("BURNER");
+
+#[openbrush::trait_definition]
 pub trait PSP1155Burnable: PSP1155 {
     /// Destroys `amount` tokens of token type `id` from the user
     ///
     /// See [`PSP1155::_burn`].
     #[ink(message)]
-    fn burn(&mut self, id: Id, amount: Balance) {
+    fn burn(&mut self, id: Id, amount: Balance) -> Result<(), PSP1155Error> {
+        self._guard_paused()?;
         self._burn(Self::env().caller(), id, amount);
+        Ok(())
     }
 
     /// Destroys `amount` tokens of token type `id` from `from`
     ///
     /// See [`PSP1155::_burn`].
     #[ink(message)]
-    fn burn_from(&mut self, from: AccountId, id: Id, amount: Balance) {
-        assert!(
-            self.is_approved_for_all(from, Self::env().caller()),
-            "{}",
-            PSP1155Error::ApproveRequired.as_ref()
-        );
-
+    fn burn_from(&mut self, from: AccountId, id: Id, amount: Balance) -> Result<(), PSP1155Error> {
+        self._check_burn_authorized(from)?;
+        self._guard_paused()?;
         self._burn(from, id, amount);
+        Ok(())
     }
 
     /// Destroys `ids_amounts[i].1` of token type `ids_amounts[i].0` from the user
     ///
     /// See [`PSP1155::_burn`].
     #[ink(message)]
-    fn burn_batch(&mut self, ids_amounts: Vec<(Id, Balance)>) {
+    fn burn_batch(&mut self, ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP1155Error> {
+        self._guard_paused()?;
         self._burn_batch(Self::env().caller(), ids_amounts);
+        Ok(())
     }
 
-    /// Destroys `ids_amounts[i].1` of token type `ids_amounts[i].0` from `from` 
+    /// Destroys `ids_amounts[i].1` of token type `ids_amounts[i].0` from `from`
     ///
     /// See [`PSP1155::_burn`].
     #[ink(message)]
-    fn burn_batch_from(&mut self, from: AccountId, ids_amounts: Vec<(Id, Balance)>) {
-        assert!(
-            self.is_approved_for_all(from, Self::env().caller()),
-            "{}",
-            PSP1155Error::ApproveRequired.as_ref()
-        );
-
+    fn burn_batch_from(&mut self, from: AccountId, ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP1155Error> {
+        self._check_burn_authorized(from)?;
+        self._guard_paused()?;
         self._burn_batch(from, ids_amounts);
+        Ok(())
+    }
+
+    /// Halts the burn path while the contract is paused, when the `pausable` feature is enabled.
+    ///
+    /// Embed [`pausable::Data`] alongside `PSP1155Data` and implement [`pausable::Pausable`] to
+    /// use this; the same guard backs the mint and transfer paths on the PSP37 side so an
+    /// emergency stop halts the token atomically. `PSP1155Burnable` does not require `Pausable` as
+    /// a supertrait, so contracts that never opt into pausability aren't forced to embed it.
+    #[cfg(feature = "pausable")]
+    fn _guard_paused(&self) -> Result<(), PSP1155Error>
+    where
+        Self: Pausable,
+    {
+        self._when_not_paused().map_err(|_| PSP1155Error::Custom(String::from(PausableError::Paused.as_ref())))
+    }
+
+    /// No-op when the `pausable` feature is disabled.
+    #[cfg(not(feature = "pausable"))]
+    fn _guard_paused(&self) -> Result<(), PSP1155Error> {
+        Ok(())
+    }
+
+    /// Authorizes `burn_from`/`burn_batch_from`. Requires the `BURNER` role when the
+    /// `access_control` feature is enabled; otherwise falls back to the caller being an approved
+    /// operator for `from`.
+    #[cfg(feature = "access_control")]
+    fn _check_burn_authorized(&self, _from: AccountId) -> Result<(), PSP1155Error>
+    where
+        Self: AccessControl,
+    {
+        match self._has_role(BURNER, &Self::env().caller()) {
+            true => Ok(()),
+            false => Err(PSP1155Error::Custom(String::from(AccessControlError::MissingRole.as_ref()))),
+        }
+    }
+
+    /// Authorizes `burn_from`/`burn_batch_from` via the caller being an approved operator for
+    /// `from`. See the `access_control`-gated variant for role-based authorization.
+    #[cfg(not(feature = "access_control"))]
+    fn _check_burn_authorized(&self, from: AccountId) -> Result<(), PSP1155Error> {
+        match self.is_approved_for_all(from, Self::env().caller()) {
+            true => Ok(()),
+            false => Err(PSP1155Error::ApproveRequired),
+        }
     }
 }
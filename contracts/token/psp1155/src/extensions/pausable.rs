@@ -0,0 +1,10 @@
+/// Extension of [`PSP1155`] that lets any account pause/unpause the contract. While paused, the
+/// burn messages of `PSP1155Burnable` (with the `pausable` feature) fail; transfers and minting
+/// are not halted.
+///
+/// See [`Pausable`].
+use crate::traits::*;
+use pausable::Pausable;
+
+#[openbrush::trait_definition]
+pub trait PSP1155Pausable: PSP1155 + Pausable {}
@@ -0,0 +1,71 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::traits::psp37::Id;
+use ink_prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+/// Typed representation of the events the PSP37 extensions emit, mirroring
+/// `psp721::traits::PSP721Event` for the multi-token case.
+///
+/// Implement a single [`PSP37EventEmitter::_emit_event`] once to map these onto concrete
+/// `#[ink(event)]` definitions, instead of overriding a separate no-op method per event kind.
+/// Overriding `_emit_event` with a mock that records `event` into a `Vec` makes the extensions'
+/// logic unit-testable against the event stream it produces. `AttributeSet` is emitted by
+/// `extensions::metadata::PSP37Metadata::_set_attribute`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PSP37Event {
+    Transfer {
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        ids_amounts: Vec<(Id, Balance)>,
+    },
+    Approval {
+        owner: AccountId,
+        operator: AccountId,
+        id: Option<Id>,
+        approved: bool,
+    },
+    AttributeSet {
+        id: Id,
+        key: Vec<u8>,
+        data: Vec<u8>,
+    },
+}
+
+/// Dispatcher for [`PSP37Event`], the same shape as `PSP721`'s own `_emit_event`: a default
+/// no-op method on a trait the concrete contract implements once, so it can override `_emit_event`
+/// to map each variant onto a concrete `#[ink(event)]` (there is no blanket impl here, so
+/// implementing `PSP37EventEmitter` for a contract and overriding `_emit_event` is a normal,
+/// coherence-safe trait impl rather than a conflict).
+///
+/// Extensions that emit PSP37 events (`PSP37Mintable`, `PSP37Allowance`, `PSP37Metadata`) add this
+/// as a supertrait so their default methods can call `self._emit_event(...)`. The PSP37 core trait
+/// (`mint`/`burn`/`transfer` on `PSP37` itself) isn't part of this checkout, so its call sites
+/// aren't wired here; add `PSP37EventEmitter` as a supertrait there too for full coverage.
+pub trait PSP37EventEmitter: crate::traits::psp37::Internal {
+    /// Emits `event`. Does nothing by default; override in the concrete contract to map each
+    /// `PSP37Event` variant onto its `#[ink(event)]` definition.
+    fn _emit_event(&self, _event: PSP37Event) {}
+}
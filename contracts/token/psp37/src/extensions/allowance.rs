@@ -0,0 +1,136 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::events::{
+    PSP37Event,
+    PSP37EventEmitter,
+};
+pub use crate::traits::psp37::{
+    Id,
+    PSP37Error,
+};
+use ink_prelude::{
+    string::String,
+    vec,
+    vec::Vec,
+};
+use ink_storage::Mapping;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+};
+
+/// Storage used by the [`PSP37Allowance`] extension.
+#[derive(Default, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Data {
+    /// The remaining amount of a given token `id` that `spender` may transfer out of `owner`.
+    pub allowances: Mapping<(AccountId, AccountId, Id), Balance>,
+}
+
+/// Extension of [`PSP37`](crate::traits::psp37::PSP37) that lets a holder grant `spender` an
+/// allowance bounded to a specific token `id`, instead of the all-or-nothing
+/// `is_approved_for_all` operator approval.
+#[openbrush::trait_definition]
+pub trait PSP37Allowance: Storage<Data> + crate::traits::psp37::Internal + PSP37EventEmitter {
+    /// Returns the amount of token `id` that `spender` is still allowed to transfer out of
+    /// `owner`. Operator-for-all approval is unlimited and isn't reflected here.
+    #[ink(message)]
+    fn allowance(&self, owner: AccountId, spender: AccountId, id: Id) -> Balance {
+        self.data().allowances.get(&(owner, spender, id)).unwrap_or(0)
+    }
+
+    /// Sets `spender`'s allowance over the caller's token `id` to exactly `value`.
+    #[ink(message)]
+    fn approve(&mut self, spender: AccountId, id: Id, value: Balance) -> Result<(), PSP37Error> {
+        self._approve_allowance(Self::env().caller(), spender, id, value)
+    }
+
+    /// Increases `spender`'s allowance over the caller's token `id` by `delta_value`.
+    #[ink(message)]
+    fn increase_allowance(&mut self, spender: AccountId, id: Id, delta_value: Balance) -> Result<(), PSP37Error> {
+        let owner = Self::env().caller();
+        let new_value = self
+            .allowance(owner, spender, id)
+            .checked_add(delta_value)
+            .ok_or_else(|| PSP37Error::Custom(String::from("PSP37Allowance: allowance overflow")))?;
+        self._approve_allowance(owner, spender, id, new_value)
+    }
+
+    /// Decreases `spender`'s allowance over the caller's token `id` by `delta_value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PSP37Error::Custom` if `delta_value` is greater than the current allowance.
+    #[ink(message)]
+    fn decrease_allowance(&mut self, spender: AccountId, id: Id, delta_value: Balance) -> Result<(), PSP37Error> {
+        let owner = Self::env().caller();
+        let allowance = self.allowance(owner, spender, id);
+        if allowance < delta_value {
+            return Err(PSP37Error::Custom(String::from("PSP37Allowance: insufficient allowance")))
+        }
+        self._approve_allowance(owner, spender, id, allowance - delta_value)
+    }
+
+    /// Transfers `value` of token `id` from `owner` to `to`, debiting the caller's allowance
+    /// unless the caller is `owner` or an approved operator for `owner`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PSP37Error::Custom` if the caller's allowance is smaller than `value`.
+    #[ink(message)]
+    fn transfer_from(
+        &mut self,
+        owner: AccountId,
+        to: AccountId,
+        id: Id,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error> {
+        let caller = Self::env().caller();
+        if caller != owner && !self.is_approved_for_all(owner, caller) {
+            let allowance = self.allowance(owner, caller, id);
+            if allowance < value {
+                return Err(PSP37Error::Custom(String::from("PSP37Allowance: insufficient allowance")))
+            }
+            // Debit the allowance only after the transfer itself succeeds: an `Err` return from an
+            // ink! message still commits any storage writes made before it, so debiting first
+            // would spend the allowance even if `_transfer_from` rejects the transfer (e.g. a
+            // paused token).
+            self._transfer_from(owner, to, vec![(id, value)], data)?;
+            return self._approve_allowance(owner, caller, id, allowance - value)
+        }
+        self._transfer_from(owner, to, vec![(id, value)], data)
+    }
+
+    /// Sets `spender`'s allowance over `owner`'s token `id` to exactly `value`.
+    fn _approve_allowance(&mut self, owner: AccountId, spender: AccountId, id: Id, value: Balance) -> Result<(), PSP37Error> {
+        self.data().allowances.insert(&(owner, spender, id), &value);
+        self._emit_event(PSP37Event::Approval {
+            owner,
+            operator: spender,
+            id: Some(id),
+            approved: value > 0,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::events::{
+    PSP37Event,
+    PSP37EventEmitter,
+};
+pub use crate::traits::psp37::Id;
+use ink_prelude::vec::Vec;
+use ink_storage::Mapping;
+use openbrush::traits::Storage;
+
+/// Storage used by the [`PSP37Metadata`] extension.
+#[derive(Default, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Data {
+    pub attributes: Mapping<(Id, Vec<u8>), Vec<u8>>,
+}
+
+/// Extension of [`PSP37`](crate::traits::psp37::PSP37) that lets a token carry arbitrary
+/// `key` => `data` attributes for a given `id`, e.g. name/symbol/decimals.
+#[openbrush::trait_definition]
+pub trait PSP37Metadata: Storage<Data> + PSP37EventEmitter {
+    /// Returns the attribute `key` stored for token `id`, if any.
+    #[ink(message)]
+    fn get_attribute(&self, id: Id, key: Vec<u8>) -> Option<Vec<u8>> {
+        self.data().attributes.get(&(id, key))
+    }
+
+    /// Sets the attribute `key` of token `id` to `data`. On success an `AttributeSet` event is
+    /// emitted.
+    fn _set_attribute(&mut self, id: Id, key: Vec<u8>, data: Vec<u8>) {
+        self.data().attributes.insert(&(id, key.clone()), &data);
+        self._emit_event(PSP37Event::AttributeSet { id, key, data });
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::events::{
+    PSP37Event,
+    PSP37EventEmitter,
+};
+pub use crate::traits::psp37::{
+    Id,
+    PSP37Error,
+};
+use ink_prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+#[cfg(feature = "access_control")]
+use access_control::{
+    AccessControlError,
+    RoleType,
+};
+
+/// Role required to call `mint` when the `access_control` feature is enabled, instead of the
+/// default open mint.
+#[cfg(feature = "access_control")]
+pub const MINTER: RoleType = ink_lang::selector_id!("MINTER");
+
+/// Extension of [`PSP37`](crate::traits::psp37::PSP37) that gives accounts the ability to mint
+/// new tokens.
+#[openbrush::trait_definition]
+pub trait PSP37Mintable: crate::traits::psp37::Internal + PSP37EventEmitter {
+    /// Mints `ids_amounts[i].1` of token type `ids_amounts[i].0` to `account`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AccessControlError::MissingRole` (as `PSP37Error::Custom`) if the caller doesn't
+    /// hold the `MINTER` role, when the `access_control` feature is enabled.
+    #[ink(message)]
+    fn mint(&mut self, account: AccountId, ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
+        self._check_mint_authorized()?;
+        self._mint_to(account, ids_amounts.clone())?;
+        self._emit_event(PSP37Event::Transfer {
+            from: None,
+            to: Some(account),
+            ids_amounts,
+        });
+        Ok(())
+    }
+
+    /// Authorizes `mint`. Requires the `MINTER` role when the `access_control` feature is
+    /// enabled; otherwise mint is left open to any caller.
+    #[cfg(feature = "access_control")]
+    fn _check_mint_authorized(&self) -> Result<(), PSP37Error>
+    where
+        Self: access_control::AccessControl,
+    {
+        match self._has_role(MINTER, &Self::env().caller()) {
+            true => Ok(()),
+            false => Err(PSP37Error::Custom(ink_prelude::string::String::from(
+                AccessControlError::MissingRole.as_ref(),
+            ))),
+        }
+    }
+
+    /// Authorizes `mint`. Open to any caller; see the `access_control`-gated variant for
+    /// role-based authorization.
+    #[cfg(not(feature = "access_control"))]
+    fn _check_mint_authorized(&self) -> Result<(), PSP37Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::psp37::{
+    Id,
+    PSP37Error,
+};
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+use pausable::Pausable;
+
+/// Extension of [`PSP37`](crate::traits::psp37::PSP37) that lets any account halt
+/// mint, burn and transfer until it is unpaused again.
+///
+/// Backed by the same [`pausable::Data`]/[`pausable::Pausable`] module the PSP1155 side uses, so
+/// a contract that implements both standards shares a single pause flag rather than each token
+/// tracking its own state. On the PSP1155 side that flag halts only the `PSP1155Burnable` burn
+/// messages; PSP1155 transfers and minting are not halted.
+///
+/// `PSP37Pausable` also extends `psp37::Internal` so the blanket [`crate::traits::psp37::Transfer`]
+/// impl below is a refinement of the bound openbrush's other `min_specialization`-based extensions
+/// (e.g. `PSP37Mintable`) already key off of, keeping the two safely non-overlapping.
+#[openbrush::trait_definition]
+pub trait PSP37Pausable: Pausable + crate::traits::psp37::Internal {}
+
+/// Wires [`pausable::Pausable::_when_not_paused`] into the `psp37::Transfer` hook so mint, burn
+/// and transfer halt atomically while the contract is paused.
+impl<T: PSP37Pausable> crate::traits::psp37::Transfer for T {
+    default fn _before_token_transfer(
+        &mut self,
+        _from: Option<&AccountId>,
+        _to: Option<&AccountId>,
+        _ids: &Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error> {
+        self._when_not_paused()
+            .map_err(|error| PSP37Error::Custom(String::from(error.as_ref())))
+    }
+
+    default fn _after_token_transfer(
+        &mut self,
+        _from: Option<&AccountId>,
+        _to: Option<&AccountId>,
+        _ids: &Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error> {
+        Ok(())
+    }
+}
@@ -5,7 +5,6 @@ use brush::{
         AccountId,
         AccountIdExt,
         InkStorage,
-        ZERO_ADDRESS,
     },
 };
 use ink_env::{
@@ -35,7 +34,12 @@ pub type Id = [u8; 32];
 #[cfg_attr(feature = "std", derive(StorageLayout))]
 pub struct PSP721Data {
     pub token_owner: StorageHashMap<Id, AccountId>,
-    pub token_approvals: StorageHashMap<Id, AccountId>,
+    /// The current approval for each token, alongside the approval ID it was granted under.
+    pub token_approvals: StorageHashMap<Id, (AccountId, u64)>,
+    /// Monotonically increasing approval ID per token. Bumped on every transfer (even when no
+    /// approval is set) so that an approval ID captured before a transfer can't be replayed
+    /// against the token afterwards.
+    pub token_approval_id: StorageHashMap<Id, u64>,
     pub owned_tokens_count: StorageHashMap<AccountId, u32>,
     pub operator_approvals: StorageHashMap<(AccountId, AccountId), bool>,
 }
@@ -60,6 +64,26 @@ pub enum PSP721Error {
     NotAllowed,
 }
 
+/// Typed representation of the events `PSP721`'s core logic emits. `id: None` on `Approval`
+/// means the event is for `set_approval_for_all` rather than a single token.
+///
+/// Implement [`PSP721::_emit_event`] once to map these onto concrete `#[ink(event)]`
+/// definitions, instead of overriding a separate no-op method per event kind.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PSP721Event {
+    Transfer {
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        id: Id,
+    },
+    Approval {
+        owner: AccountId,
+        operator: AccountId,
+        id: Option<Id>,
+        approved: bool,
+    },
+}
+
 /// Contract module which provides a basic implementation of non fungible token.
 ///
 /// This module is used through embedding of `PSP721Data` and implementation of `PSP721` and
@@ -83,7 +107,7 @@ pub trait PSP721: PSP721Storage {
     /// Returns the approved account ID for this token if any.
     #[ink(message)]
     fn get_approved(&self, id: Id) -> Option<AccountId> {
-        self.get().token_approvals.get(&id).cloned()
+        self.get().token_approvals.get(&id).map(|(account, _)| *account)
     }
 
     /// Returns `true` if the operator is approved by the owner.
@@ -98,7 +122,7 @@ pub trait PSP721: PSP721Storage {
     ///
     /// # Errors
     ///
-    /// Panics with `NotAllowed` error if it is self approve.
+    /// Returns `NotAllowed` error if it is self approve.
     #[ink(message)]
     fn set_approval_for_all(&mut self, to: AccountId, approved: bool) -> Result<(), PSP721Error> {
         self._approve_for_all(to, approved)?;
@@ -111,7 +135,10 @@ pub trait PSP721: PSP721Storage {
     ///
     /// # Errors
     ///
-    /// Panics with `NotAllowed` error if caller is not owner of `id`.
+    /// Returns `TokenNotFound` error if `id` doesn't exist.
+    ///
+    /// Returns `NotAllowed` error if caller is neither the owner of `id` nor an approved operator
+    /// of its owner, or if `to` is the zero address.
     #[ink(message)]
     fn approve(&mut self, to: AccountId, id: Id) -> Result<(), PSP721Error> {
         self._approve_for(to, id)?;
@@ -124,50 +151,64 @@ pub trait PSP721: PSP721Storage {
     ///
     /// # Errors
     ///
-    /// Panics with `TokenNotFound` error if `id` is not exist.
+    /// Returns `TokenNotFound` error if `id` doesn't exist.
+    ///
+    /// Returns `NotOwner` error if `from` is not the current owner of `id`.
     ///
-    /// Panics with `NotApproved` error if `from` doesn't have allowance for transferring.
+    /// Returns `NotApproved` error if the caller doesn't have allowance for transferring.
     #[ink(message)]
     fn transfer_from(&mut self, from: AccountId, to: AccountId, id: Id) -> Result<(), PSP721Error> {
         self._transfer_token_from(&from, to.clone(), id)?;
-        self._emit_transfer_event(from, to, id);
+        self._emit_event(PSP721Event::Transfer {
+            from: Some(from),
+            to: Some(to),
+            id,
+        });
         Ok(())
     }
 
     /// Transfers token with `id` from `from` to `to`. Also some `data` can be passed.
     ///
-    /// On success a `Transfer` event is emitted.
+    /// The balance update happens before `to` is asked to accept the transfer via
+    /// `on_psp721_received`; if `to` rejects it, the transfer is reverted by `_resolve_transfer`
+    /// instead of being left half-applied. The `Transfer` event is deferred until
+    /// `_resolve_transfer` confirms `to` actually accepted it, so observers never see a transfer
+    /// that was immediately reverted.
     ///
     /// # Errors
     ///
-    /// Panics with `TokenNotFound` error if `id` is not exist.
+    /// Returns `TokenNotFound` error if `id` doesn't exist.
+    ///
+    /// Returns `NotOwner` error if `from` is not the current owner of `id`.
     ///
-    /// Panics with `NotApproved` error if `from` doesn't have allowance for transferring.
+    /// Returns `NotApproved` error if the caller doesn't have allowance for transferring.
     ///
-    /// Panics with `CallFailed` error if `to` doesn't accept transfer.
+    /// Returns `CallFailed` error if `to` doesn't accept transfer.
     #[ink(message)]
     fn safe_transfer_from(&mut self, from: AccountId, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP721Error> {
+        let caller = Self::env().caller();
+        let previous_approval = self.get().token_approvals.get(&id).cloned();
+
         self._transfer_token_from(&from, to.clone(), id)?;
-        self._call_contract_transfer(Self::env().caller(), from, to, id, data)?;
-        self._emit_transfer_event(from, to, id);
-        Ok(())
+
+        let result = self._call_contract_transfer(caller, from, to, id, data);
+        self._resolve_transfer(from, to, id, previous_approval, result)
     }
 
     // Helper functions
 
-    /// Emits transfer event. This method must be implemented in derived implementation
-    fn _emit_transfer_event(&self, _from: AccountId, _to: AccountId, _id: Id) {}
-
-    /// Emits approval event. This method must be implemented in derived implementation
-    fn _emit_approval_event(&self, _from: AccountId, _to: AccountId, _id: Id) {}
-
-    /// Emits approval for all event. This method must be implemented in derived implementation
-    fn _emit_approval_for_all_event(&self, _owner: AccountId, _operator: AccountId, _approved: bool) {}
+    /// Emits `event`. Does nothing by default; override in the concrete contract to map each
+    /// `PSP721Event` variant onto its `#[ink(event)]` definition. Business logic stays
+    /// unit-testable against the event stream by overriding this with a mock that records
+    /// `event` into a `Vec` instead of emitting it.
+    fn _emit_event(&self, _event: PSP721Event) {}
 
     /// Approves or disapproves the operator to transfer all tokens of the caller.
     fn _approve_for_all(&mut self, to: AccountId, approved: bool) -> Result<(), PSP721Error> {
         let caller = Self::env().caller();
-        assert_ne!(to, caller, "{}", PSP721Error::NotAllowed.as_ref());
+        if to == caller {
+            return Err(PSP721Error::NotAllowed)
+        }
         if self._approved_for_all(caller, to) {
             self.get_mut()
                 .operator_approvals
@@ -177,21 +218,37 @@ pub trait PSP721: PSP721Storage {
         } else {
             self.get_mut().operator_approvals.insert((caller, to), approved);
         }
-        self._emit_approval_for_all_event(caller, to, approved);
+        self._emit_event(PSP721Event::Approval {
+            owner: caller,
+            operator: to,
+            id: None,
+            approved,
+        });
         Ok(())
     }
 
     /// Approve the passed AccountId to transfer the specified token on behalf of the message's sender.
     fn _approve_for(&mut self, to: AccountId, id: Id) -> Result<(), PSP721Error> {
         let caller = Self::env().caller();
-        let owner = self._owner_of(&id);
-        if !(owner == Some(caller) || self._approved_for_all(owner.expect("PSP721Error with AccountId"), caller)) {
-            panic!("{}", PSP721Error::NotAllowed.as_ref());
+        let owner = match self._owner_of(&id) {
+            Some(owner) => owner,
+            None => return Err(PSP721Error::TokenNotFound),
         };
-        assert!(!to.is_zero(), "{}", PSP721Error::NotAllowed.as_ref());
+        if !(owner == caller || self._approved_for_all(owner, caller)) {
+            return Err(PSP721Error::NotAllowed)
+        }
+        if to.is_zero() {
+            return Err(PSP721Error::NotAllowed)
+        }
 
-        self.get_mut().token_approvals.insert(id, to);
-        self._emit_approval_event(caller, to, id);
+        let approval_id = self._bump_approval_id(id);
+        self.get_mut().token_approvals.insert(id, (to, approval_id));
+        self._emit_event(PSP721Event::Approval {
+            owner: caller,
+            operator: to,
+            id: Some(id),
+            approved: true,
+        });
         Ok(())
     }
 
@@ -212,41 +269,64 @@ pub trait PSP721: PSP721Storage {
     /// Returns true if the AccountId `from` is the owner of token `id`
     /// or it has been approved on behalf of the token `id` owner.
     fn _approved_or_owner(&self, from: Option<AccountId>, id: &Id) -> bool {
-        let owner = self._owner_of(id);
-        !from.unwrap_or_default().is_zero()
-            && (from == owner
-                || from == self.get().token_approvals.get(id).cloned()
-                || self._approved_for_all(
-                    owner.expect("PSP721Error with AccountId"),
-                    from.expect("PSP721Error with AccountId"),
-                ))
+        match (from, self._owner_of(id)) {
+            (Some(from), Some(owner)) if !from.is_zero() => {
+                from == owner || self._is_current_approval(from, id) || self._approved_for_all(owner, from)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if `account` holds the approval currently stored for `id`, i.e. both the
+    /// approved account and the approval ID it was granted under still match. Comparing the ID
+    /// as well as the account means an approval captured before a transfer is never mistaken for
+    /// one granted afterwards, even if `to` happens to be re-approved to the same account later.
+    fn _is_current_approval(&self, account: AccountId, id: &Id) -> bool {
+        match self.get().token_approvals.get(id) {
+            Some((approved, approval_id)) => {
+                *approved == account && Some(*approval_id) == self.get().token_approval_id.get(id).cloned()
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the next approval ID for `id` and persists it. Called whenever `id` changes
+    /// approval or owner, so an approval ID captured before that change can't be replayed.
+    fn _bump_approval_id(&mut self, id: Id) -> u64 {
+        let next = self.get().token_approval_id.get(&id).cloned().unwrap_or(0) + 1;
+        self.get_mut().token_approval_id.insert(id, next);
+        next
     }
 
     /// Transfers token `id` `from` the sender to the `to` AccountId.
+    ///
+    /// Re-verifies that `from` is the current owner of `id` (not merely approved) before moving
+    /// it, so a stale or forged `from` can't be used to move a token it no longer owns.
     fn _transfer_token_from(&mut self, from: &AccountId, to: AccountId, id: Id) -> Result<(), PSP721Error> {
         let caller = Self::env().caller();
-        assert!(
-            self.get().token_owner.get(&id).is_some(),
-            "{}",
-            PSP721Error::TokenNotFound.as_ref()
-        );
-        assert!(
-            self._approved_or_owner(Some(caller), &id),
-            "{}",
-            PSP721Error::NotApproved.as_ref()
-        );
+        match self._owner_of(&id) {
+            None => return Err(PSP721Error::TokenNotFound),
+            Some(owner) if owner != *from => return Err(PSP721Error::NotOwner),
+            _ => {}
+        }
+        if !self._approved_or_owner(Some(caller), &id) {
+            return Err(PSP721Error::NotApproved)
+        }
         self.get_mut().token_approvals.take(&id);
-        self._remove_from(from.clone(), id)?;
+        self._bump_approval_id(id);
+        self._remove_from(*from, id)?;
         self._add_to(to, id)?;
         Ok(())
     }
 
     fn _add_to(&mut self, to: AccountId, id: Id) -> Result<(), PSP721Error> {
-        assert!(!to.is_zero(), "{}", PSP721Error::NotAllowed.as_ref());
-        match self.get_mut().token_owner.entry(id) {
-            Entry::Vacant(vacant) => vacant.insert(to),
-            Entry::Occupied(_) => panic!("{}", PSP721Error::TokenExists.as_ref()),
-        };
+        if to.is_zero() {
+            return Err(PSP721Error::NotAllowed)
+        }
+        if self.get().token_owner.get(&id).is_some() {
+            return Err(PSP721Error::TokenExists)
+        }
+        self.get_mut().token_owner.insert(id, to);
 
         self.get_mut()
             .owned_tokens_count
@@ -256,18 +336,23 @@ pub trait PSP721: PSP721Storage {
         Ok(())
     }
 
-    fn _remove_from(&mut self, caller: AccountId, id: Id) -> Result<(), PSP721Error> {
+    fn _remove_from(&mut self, from: AccountId, id: Id) -> Result<(), PSP721Error> {
         let occupied = match self.get_mut().token_owner.entry(id) {
-            Entry::Vacant(_) => panic!("{}", PSP721Error::TokenNotFound.as_ref()),
+            Entry::Vacant(_) => return Err(PSP721Error::TokenNotFound),
             Entry::Occupied(occupied) => occupied,
         };
-        assert_eq!(occupied.get(), &caller, "{}", PSP721Error::NotOwner.as_ref());
+        if occupied.get() != &from {
+            return Err(PSP721Error::NotOwner)
+        }
         occupied.remove_entry();
 
-        self.get_mut().owned_tokens_count.entry(caller).and_modify(|v| *v -= 1);
+        self.get_mut().owned_tokens_count.entry(from).and_modify(|v| *v -= 1);
         Ok(())
     }
 
+    /// Invokes `on_psp721_received` on `to`. Called after the balance update has already been
+    /// applied; the result is handed to `_resolve_transfer`, which reverts the transfer if `to`
+    /// rejected it.
     fn _call_contract_transfer(
         &self,
         operator: AccountId,
@@ -278,36 +363,67 @@ pub trait PSP721: PSP721Storage {
     ) -> Result<(), PSP721Error> {
         let mut receiver: PSP721Receiver = FromAccountId::from_account_id(to);
         match receiver.call_mut().on_psp721_received(operator, from, id, data).fire() {
-            Ok(result) => {
-                match result {
-                    Ok(_) => Ok(()),
-                    _ => panic!("{}", PSP721Error::CallFailed.as_ref()),
-                }
-            }
-            Err(e) => {
-                match e {
-                    Env_error::NotCallable => Ok(()),
-                    _ => panic!("{}", PSP721Error::CallFailed.as_ref()),
-                }
-            }
+            Ok(Ok(_)) => Ok(()),
+            Ok(Err(_)) => Err(PSP721Error::CallFailed),
+            Err(Env_error::NotCallable) => Ok(()),
+            Err(_) => Err(PSP721Error::CallFailed),
         }
     }
 
+    /// Inspects the outcome of `_call_contract_transfer`. On acceptance, emits the `Transfer`
+    /// event for the transfer that `_transfer_token_from` already applied. On rejection, reverts
+    /// that ownership change instead: `id` is moved back to `from` and `previous_approval` is
+    /// reinstated, and no event is emitted, since from an observer's perspective the transfer
+    /// never happened. Returns `result` unchanged either way, so the caller always sees the final
+    /// success/revert outcome.
+    fn _resolve_transfer(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        id: Id,
+        previous_approval: Option<(AccountId, u64)>,
+        result: Result<(), PSP721Error>,
+    ) -> Result<(), PSP721Error> {
+        if result.is_ok() {
+            self._emit_event(PSP721Event::Transfer {
+                from: Some(from),
+                to: Some(to),
+                id,
+            });
+            return Ok(())
+        }
+
+        self._remove_from(to, id)?;
+        self._add_to(from, id)?;
+        if let Some((approved, _)) = previous_approval {
+            // `_transfer_token_from` already bumped `token_approval_id` for `id` before this
+            // revert, so reinstate the approval under the *current* counter value rather than
+            // its stale pre-transfer one, or `_is_current_approval` would read it as invalid.
+            let approval_id = self.get().token_approval_id.get(&id).cloned().unwrap_or(0);
+            self.get_mut().token_approvals.insert(id, (approved, approval_id));
+        }
+
+        result
+    }
+
     fn _mint(&mut self, id: Id) -> Result<(), PSP721Error> {
         let to = Self::env().caller();
         self._mint_to(to, id)
     }
 
     fn _mint_to(&mut self, to: AccountId, id: Id) -> Result<(), PSP721Error> {
-        let result = self._add_to(to, id);
-        self._emit_transfer_event(ZERO_ADDRESS.into(), to, id);
-        result
+        self._add_to(to, id)?;
+        self._bump_approval_id(id);
+        self._emit_event(PSP721Event::Transfer { from: None, to: Some(to), id });
+        Ok(())
     }
 
     fn _burn_from(&mut self, from: AccountId, id: Id) -> Result<(), PSP721Error> {
-        let result = self._remove_from(from, id);
-        self._emit_transfer_event(from, ZERO_ADDRESS.into(), id);
-        result
+        self._remove_from(from, id)?;
+        self.get_mut().token_approvals.take(&id);
+        self._bump_approval_id(id);
+        self._emit_event(PSP721Event::Transfer { from: Some(from), to: None, id });
+        Ok(())
     }
 
     fn _burn(&mut self, id: Id) -> Result<(), PSP721Error> {
@@ -0,0 +1,78 @@
+use access_control::{
+    AccessControl,
+    AccessControlError,
+    DEFAULT_ADMIN_ROLE,
+};
+use openbrush::traits::Hash;
+
+/// The Upgradeable error type. Contract will throw one of these errors.
+#[derive(strum_macros::AsRefStr, Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum UpgradeableError {
+    /// Returned if the caller doesn't hold `DEFAULT_ADMIN_ROLE`.
+    CallerIsNotOwner,
+    /// Returned if `pallet_contracts::Chain::set_code_hash` rejects the code hash.
+    SetCodeHashFailed,
+}
+
+/// Contract module which gives an owner-gated path to swap the contract's code, plus a separate
+/// owner-gated `migrate` message to run migration logic once that new code is actually live.
+///
+/// `set_code_hash` only changes the code used by messages dispatched *after* the current call
+/// returns; the call that invokes it keeps running under the old code. So `upgrade` cannot run a
+/// migration hook itself in the context of the new code — it can only swap the code and return.
+/// Once `upgrade` has been submitted and included, the owner sends a follow-up `migrate` call,
+/// which genuinely executes under the new code and can reshape storage for the new layout.
+///
+/// This module is used through implementation of `Upgradeable` on a contract that also embeds
+/// [`access_control::Data`], so ownership of the upgrade is the same `DEFAULT_ADMIN_ROLE` used
+/// elsewhere in the contract.
+#[openbrush::trait_definition]
+pub trait Upgradeable: AccessControl {
+    /// Replaces the contract's code with the code stored under `code_hash`.
+    ///
+    /// Does not run `_on_upgrade`: the in-flight call continues executing under the old code even
+    /// after `set_code_hash` succeeds, so any migration must happen in a later call. Follow this
+    /// up with `migrate` once the upgrade has landed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UpgradeableError::CallerIsNotOwner` if the caller doesn't hold
+    /// `DEFAULT_ADMIN_ROLE`.
+    ///
+    /// Returns `UpgradeableError::SetCodeHashFailed` if the runtime rejects `code_hash`.
+    #[ink(message)]
+    fn upgrade(&mut self, code_hash: Hash) -> Result<(), UpgradeableError> {
+        self._check_role(DEFAULT_ADMIN_ROLE, &Self::env().caller())
+            .map_err(|_: AccessControlError| UpgradeableError::CallerIsNotOwner)?;
+
+        Self::env()
+            .set_code_hash(&code_hash)
+            .map_err(|_| UpgradeableError::SetCodeHashFailed)?;
+
+        Ok(())
+    }
+
+    /// Runs `_on_upgrade` migration logic. Call this after `upgrade` has landed, so it executes
+    /// under the new code and any storage reads/writes here observe the new layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UpgradeableError::CallerIsNotOwner` if the caller doesn't hold
+    /// `DEFAULT_ADMIN_ROLE`.
+    #[ink(message)]
+    fn migrate(&mut self) -> Result<(), UpgradeableError> {
+        self._check_role(DEFAULT_ADMIN_ROLE, &Self::env().caller())
+            .map_err(|_: AccessControlError| UpgradeableError::CallerIsNotOwner)?;
+
+        self._on_upgrade()
+    }
+
+    // Helper functions
+
+    /// Runs migration logic when `migrate` is called after `upgrade` has swapped the contract's
+    /// code. Does nothing by default; override to migrate storage between versions.
+    fn _on_upgrade(&mut self) -> Result<(), UpgradeableError> {
+        Ok(())
+    }
+}